@@ -7,9 +7,22 @@
 //!
 //! `"serde"`: Implements [`serde::Deserialize`] and [`serde::Serialize`] on [`Cow`].
 //!
+//! `"serde-borrow"`: Requires `"serde"` and serde's own `"alloc"` feature (the `Cargo.toml` of a consumer enabling this must declare `serde-borrow = ["serde", "serde/alloc"]`, since [`serde::de::Visitor::visit_string`] and [`serde::de::Visitor::visit_byte_buf`] are only trait members when serde is built with `"alloc"`).
+//!
+//! **This is a breaking change in scope, not just an optimization:** enabling `"serde-borrow"` removes [`serde::Deserialize`] for every `Cow<'a, T, R>` *except* the two hardcoded specializations below, because Rust's coherence rules forbid an impl for arbitrary `T`/`R` that overlaps with concrete ones. Only enable it if every `Cow` instantiation you deserialize in your crate is one of:
+//!
+//! * `Cow<'a, String, str>`
+//! * `Cow<'a, Vec<u8>, [u8]>`
+//!
+//! For these two, [`Cow::Borrowed`] is produced directly from the deserializer's input where possible, instead of always allocating.
+//!
+//! [`serde::de::Visitor::visit_string`]: https://docs.rs/serde/1.0.115/serde/de/trait.Visitor.html#method.visit_string
+//! [`serde::de::Visitor::visit_byte_buf`]: https://docs.rs/serde/1.0.115/serde/de/trait.Visitor.html#method.visit_byte_buf
+//!
 //! [`serde::Deserialize`]: https://docs.rs/serde/1.0.115/serde/trait.Deserialize.html
 //! [`serde::Serialize`]: https://docs.rs/serde/1.0.115/serde/trait.Serialize.html
 //! [`Cow`]: enum.Cow.html
+//! [`Cow::Borrowed`]: enum.Cow.html#variant.Borrowed
 //!
 //! # Examples
 //!
@@ -54,13 +67,16 @@ pub mod readme {
 	doc_comment::doctest!("../README.md");
 }
 
+#[cfg(feature = "serde-borrow")]
+extern crate alloc;
+
 use core::{
-	borrow::Borrow,
+	borrow::{Borrow, BorrowMut},
 	cmp::Ordering,
 	convert::{TryFrom, TryInto as _},
 	fmt::{self, Display, Formatter},
 	hash::{Hash, Hasher},
-	ops::Deref,
+	ops::{Add, AddAssign, Deref},
 };
 
 #[cfg(feature = "serde")]
@@ -264,6 +280,37 @@ impl<'a, T: Borrow<R>, R: ?Sized> Borrow<R> for Cow<'a, T, R> {
 	}
 }
 
+impl<'a, T: BorrowMut<R> + From<&'a R>, R: ?Sized> BorrowMut<R> for Cow<'a, T, R> {
+	/// Promotes a borrowed variant into an owned one in place (see [`make_mut`]), then returns a mutable reference into it.
+	///
+	/// Note: due to std's reflexive `impl<T> BorrowMut<T> for T`, a bare `.borrow_mut()` call on a `Cow` is usually ambiguous; bind the result to an explicitly-typed local, as below.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use cervine::Cow;
+	/// use std::borrow::BorrowMut;
+	///
+	/// let mut cow: Cow<Vec<u8>, [u8]> = Cow::Borrowed(&[1, 2, 3]);
+	/// let byte: &mut [u8] = cow.borrow_mut(); // Promotes to `Owned`.
+	/// byte[0] = 0;
+	///
+	/// assert!(cow.is_owned());
+	/// assert_eq!(cow.as_ref(), [0, 2, 3]);
+	/// ```
+	///
+	/// [`make_mut`]: #method.make_mut
+	fn borrow_mut(&mut self) -> &mut R {
+		self.make_mut().borrow_mut()
+	}
+}
+
+impl<'a, T: BorrowMut<R> + From<&'a R>, R: ?Sized> AsMut<R> for Cow<'a, T, R> {
+	fn as_mut(&mut self) -> &mut R {
+		self.borrow_mut()
+	}
+}
+
 impl<'a, T: Borrow<R>, R: Display + ?Sized> Display for Cow<'a, T, R> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		self.as_ref().fmt(f)
@@ -279,6 +326,16 @@ impl<'a, T: Clone, R: ?Sized> Clone for Cow<'a, T, R> {
 			Cow::Borrowed(r) => Self::Borrowed(r),
 		}
 	}
+
+	/// Reuses the owned allocation in place if both `self` and `source` are [`Cow::Owned`], instead of dropping and reallocating it.
+	///
+	/// [`Cow::Owned`]: enum.Cow.html#variant.Owned
+	fn clone_from(&mut self, source: &Self) {
+		match (&mut *self, source) {
+			(Cow::Owned(dest), Cow::Owned(src)) => dest.clone_from(src),
+			_ => *self = source.clone(),
+		}
+	}
 }
 
 impl<'a, T: Default, R: ?Sized> Default for Cow<'a, T, R> {
@@ -351,6 +408,105 @@ impl<'a, T: Borrow<R>, R: Hash + ?Sized> Hash for Cow<'a, T, R> {
 	}
 }
 
+/// Lets the `Add`/`AddAssign` impls on [`Cow`] recognise a cheaply-known-empty `rhs`, so that appending it never promotes a [`Cow::Borrowed`] to [`Cow::Owned`].
+///
+/// [`Cow`]: enum.Cow.html
+/// [`Cow::Borrowed`]: enum.Cow.html#variant.Borrowed
+/// [`Cow::Owned`]: enum.Cow.html#variant.Owned
+pub trait MaybeEmpty {
+	/// Returns `true` if `self` is known to contain no elements.
+	fn is_empty(&self) -> bool;
+}
+
+impl MaybeEmpty for str {
+	fn is_empty(&self) -> bool {
+		str::is_empty(self)
+	}
+}
+
+impl<U> MaybeEmpty for [U] {
+	fn is_empty(&self) -> bool {
+		<[U]>::is_empty(self)
+	}
+}
+
+impl<'a, 'b, T, R: ?Sized> AddAssign<&'b R> for Cow<'a, T, R>
+where
+	T: Borrow<R> + From<&'a R> + for<'x> AddAssign<&'x R>,
+	R: MaybeEmpty,
+{
+	/// Appends `rhs` in place.
+	///
+	/// If this value is a borrowed variant and `rhs` is [`MaybeEmpty::is_empty`], nothing happens and the value stays borrowed.
+	/// Otherwise, a borrowed variant is converted in place into an owned variant first (see [`make_mut`]), then `rhs` is appended to it.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use cervine::Cow;
+	///
+	/// let mut borrowed: Cow<String, str> = Cow::Borrowed("borrowed");
+	/// borrowed += ""; // Appending nothing to a borrowed value doesn't promote it.
+	/// assert!(borrowed.is_borrowed());
+	///
+	/// borrowed += " suffix";
+	/// assert!(borrowed.is_owned());
+	/// assert_eq!(borrowed.as_ref(), "borrowed suffix");
+	/// ```
+	///
+	/// [`make_mut`]: #method.make_mut
+	/// [`MaybeEmpty::is_empty`]: trait.MaybeEmpty.html#tymethod.is_empty
+	fn add_assign(&mut self, rhs: &'b R) {
+		if rhs.is_empty() {
+			return;
+		}
+		match self {
+			Cow::Owned(t) => *t += rhs,
+			Cow::Borrowed(r) => {
+				let mut t = T::from(*r);
+				t += rhs;
+				*self = Cow::Owned(t);
+			}
+		}
+	}
+}
+
+impl<'a, 'b, T, R: ?Sized> AddAssign<&'b Self> for Cow<'a, T, R>
+where
+	T: Borrow<R> + From<&'a R> + for<'x> AddAssign<&'x R>,
+	R: MaybeEmpty,
+{
+	fn add_assign(&mut self, rhs: &'b Self) {
+		*self += rhs.as_ref();
+	}
+}
+
+impl<'a, 'b, T, R: ?Sized> Add<&'b R> for Cow<'a, T, R>
+where
+	T: Borrow<R> + From<&'a R> + for<'x> AddAssign<&'x R>,
+	R: MaybeEmpty,
+{
+	type Output = Self;
+
+	fn add(mut self, rhs: &'b R) -> Self::Output {
+		self += rhs;
+		self
+	}
+}
+
+impl<'a, 'b, T, R: ?Sized> Add<&'b Self> for Cow<'a, T, R>
+where
+	T: Borrow<R> + From<&'a R> + for<'x> AddAssign<&'x R>,
+	R: MaybeEmpty,
+{
+	type Output = Self;
+
+	fn add(mut self, rhs: &'b Self) -> Self::Output {
+		self += rhs;
+		self
+	}
+}
+
 /// Requires `"serde"` feature.
 #[cfg(feature = "serde")]
 impl<'a, T: Borrow<R>, R: ser::Serialize + ?Sized> ser::Serialize for Cow<'a, T, R> {
@@ -362,8 +518,8 @@ impl<'a, T: Borrow<R>, R: ser::Serialize + ?Sized> ser::Serialize for Cow<'a, T,
 	}
 }
 
-/// Requires `"serde"` feature.
-#[cfg(feature = "serde")]
+/// Requires `"serde"` feature. Not available for *any* `Cow<'a, T, R>` when `"serde-borrow"` is enabled instead — see the crate-level `"serde-borrow"` docs, since that feature replaces this blanket impl with two hardcoded, zero-copy-borrowing specializations rather than extending it.
+#[cfg(all(feature = "serde", not(feature = "serde-borrow")))]
 impl<'a, 'de, T: de::Deserialize<'de>, R: ?Sized> de::Deserialize<'de> for Cow<'a, T, R> {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
@@ -382,3 +538,122 @@ impl<'a, 'de, T: de::Deserialize<'de>, R: ?Sized> de::Deserialize<'de> for Cow<'
 		}
 	}
 }
+
+/// Requires the `"serde-borrow"` feature. Borrows directly from the deserializer's input (no allocation) where possible, falling back to an owned `String` otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use cervine::Cow;
+///
+/// let borrowed: Cow<String, str> = serde_json::from_str(r#""borrowed""#).unwrap();
+/// assert!(borrowed.is_borrowed()); // No escapes: borrows straight from the input.
+///
+/// let owned: Cow<String, str> = serde_json::from_str(r#""escaped\n""#).unwrap();
+/// assert!(owned.is_owned()); // The escape forces an owned allocation.
+/// ```
+#[cfg(feature = "serde-borrow")]
+impl<'a, 'de: 'a> de::Deserialize<'de> for Cow<'a, alloc::string::String, str> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct CowStrVisitor;
+
+		impl<'a> de::Visitor<'a> for CowStrVisitor {
+			type Value = Cow<'a, alloc::string::String, str>;
+
+			fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+				formatter.write_str("a string")
+			}
+
+			fn visit_borrowed_str<E>(self, v: &'a str) -> Result<Self::Value, E>
+			where
+				E: de::Error,
+			{
+				Ok(Cow::Borrowed(v))
+			}
+
+			fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+			where
+				E: de::Error,
+			{
+				Ok(Cow::Owned(v.into()))
+			}
+
+			fn visit_string<E>(self, v: alloc::string::String) -> Result<Self::Value, E>
+			where
+				E: de::Error,
+			{
+				Ok(Cow::Owned(v))
+			}
+		}
+
+		deserializer.deserialize_str(CowStrVisitor)
+	}
+}
+
+/// Requires the `"serde-borrow"` feature. Borrows directly from the deserializer's input (no allocation) where possible, falling back to an owned `Vec<u8>` otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use cervine::Cow;
+///
+/// let borrowed: Cow<Vec<u8>, [u8]> = serde_json::from_str(r#""borrowed""#).unwrap();
+/// assert!(borrowed.is_borrowed()); // No escapes: borrows straight from the input.
+///
+/// let owned: Cow<Vec<u8>, [u8]> = serde_json::from_str(r#""escaped\n""#).unwrap();
+/// assert!(owned.is_owned()); // The escape forces an owned allocation.
+/// ```
+#[cfg(feature = "serde-borrow")]
+impl<'a, 'de: 'a> de::Deserialize<'de> for Cow<'a, alloc::vec::Vec<u8>, [u8]> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct CowBytesVisitor;
+
+		impl<'a> de::Visitor<'a> for CowBytesVisitor {
+			type Value = Cow<'a, alloc::vec::Vec<u8>, [u8]>;
+
+			fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+				formatter.write_str("a byte array")
+			}
+
+			fn visit_borrowed_bytes<E>(self, v: &'a [u8]) -> Result<Self::Value, E>
+			where
+				E: de::Error,
+			{
+				Ok(Cow::Borrowed(v))
+			}
+
+			fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+			where
+				E: de::Error,
+			{
+				Ok(Cow::Owned(v.into()))
+			}
+
+			fn visit_byte_buf<E>(self, v: alloc::vec::Vec<u8>) -> Result<Self::Value, E>
+			where
+				E: de::Error,
+			{
+				Ok(Cow::Owned(v))
+			}
+
+			fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+			where
+				A: de::SeqAccess<'a>,
+			{
+				let mut bytes = alloc::vec::Vec::new();
+				while let Some(byte) = seq.next_element()? {
+					bytes.push(byte);
+				}
+				Ok(Cow::Owned(bytes))
+			}
+		}
+
+		deserializer.deserialize_bytes(CowBytesVisitor)
+	}
+}